@@ -1,8 +1,11 @@
 #![cfg_attr(not(test), no_std)]
 #![deny(unsafe_code)]
 
+#[cfg(test)]
+mod debug_utils;
 pub mod scd4x;
 mod sensirion;
 pub mod sgp40;
+pub mod voc_index;
 
 pub use sensirion::Error;
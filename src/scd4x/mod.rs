@@ -4,6 +4,7 @@ use embedded_hal::i2c::I2c;
 use crate::sensirion::*;
 
 pub mod commands;
+pub mod settings;
 
 const ADDR: u8 = 0x62;
 
@@ -20,6 +21,24 @@ impl fmt::Display for Variant {
     }
 }
 
+/// A single CO2/temperature/humidity reading, as returned by [`SCD4x::read_measurement`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub co2_ppm: u16,
+    pub temperature_c: f32,
+    pub humidity_rh: f32,
+}
+
+impl Measurement {
+    fn from_words(words: [u16; 3]) -> Self {
+        Self {
+            co2_ppm: words[0],
+            temperature_c: -45.0 + 175.0 * (words[1] as f32) / 65535.0,
+            humidity_rh: 100.0 * (words[2] as f32) / 65535.0,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SCD4x<I2C> {
     sensor: Sensor<I2C>,
@@ -87,6 +106,405 @@ impl<I2C: I2c> SCD4x<I2C> {
             _ => Err(Error::InvalidResponse),
         }
     }
+
+    /// Starts periodic measurement, which updates a new measurement every 5 seconds.
+    /// Use [`Self::get_data_ready_status`] to poll for a result and
+    /// [`Self::read_measurement`] to read it out.
+    pub fn start_periodic_measurement(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command(&commands::START_PERIODIC_MEASUREMENTS)
+    }
+
+    /// Stops periodic measurement. The sensor needs 500 ms before it will accept
+    /// another command.
+    pub fn stop_periodic_measurement(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command(&commands::STOP_PERIODIC_MEASUREMENTS)
+    }
+
+    /// Reads out the result of a periodic or single shot measurement. Only valid
+    /// once [`Self::get_data_ready_status`] reports data is ready.
+    pub fn read_measurement(&mut self) -> Result<Measurement, Error<I2C::Error>> {
+        let words = self
+            .sensor
+            .three_words_command(&commands::READ_MEASUREMENT)?;
+
+        Ok(Measurement::from_words(words))
+    }
+
+    /// Triggers a single CO2/temperature/humidity measurement on the SCD41/SCD43.
+    /// Takes up to 5 seconds; poll [`Self::get_data_ready_status`] before calling
+    /// [`Self::read_measurement`].
+    pub fn measure_single_shot(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command(&commands::MEASURE_SINGLE_SHOT)
+    }
+
+    /// Triggers a single temperature/humidity-only measurement on the SCD41/SCD43,
+    /// without powering the CO2 sensor. Takes up to 50 ms; poll
+    /// [`Self::get_data_ready_status`] before calling [`Self::read_measurement`].
+    pub fn measure_single_shot_rht_only(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command(&commands::MEASURE_SINGLE_SHOT_RHT_ONLY)
+    }
+
+    /// Gets the temperature offset, in degrees Celsius, applied to on-chip
+    /// temperature/humidity compensation.
+    pub fn get_temperature_offset(&mut self) -> Result<f32, Error<I2C::Error>> {
+        let word = self
+            .sensor
+            .one_word_command(&commands::GET_TEMPERATURE_OFFSET)?;
+
+        Ok(175.0 * (word as f32) / 65535.0)
+    }
+
+    /// Sets the temperature offset, in degrees Celsius, applied to on-chip
+    /// temperature/humidity compensation. Takes effect after the next
+    /// measurement is read out.
+    pub fn set_temperature_offset(&mut self, offset_c: f32) -> Result<(), Error<I2C::Error>> {
+        let word = (offset_c * 65535.0 / 175.0) as u16;
+        self.sensor
+            .write_words_command(&commands::SET_TEMPERATURE_OFFSET, &[word])
+    }
+
+    /// Gets the sensor altitude, in meters above sea level, used for on-chip CO2
+    /// compensation.
+    pub fn get_sensor_altitude(&mut self) -> Result<u16, Error<I2C::Error>> {
+        self.sensor.one_word_command(&commands::GET_SENSOR_ALTITUDE)
+    }
+
+    /// Sets the sensor altitude, in meters above sea level, used for on-chip CO2
+    /// compensation. Ignored while ambient pressure compensation is active.
+    pub fn set_sensor_altitude(&mut self, altitude_m: u16) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .write_words_command(&commands::SET_SENSOR_ALTITUDE, &[altitude_m])
+    }
+
+    /// Gets the ambient pressure, in Pascal, used for on-chip CO2 compensation.
+    pub fn get_ambient_pressure(&mut self) -> Result<u32, Error<I2C::Error>> {
+        let word = self
+            .sensor
+            .one_word_command(&commands::GET_AMBIENT_PRESSURE)?;
+
+        Ok((word as u32) * 100)
+    }
+
+    /// Sets the ambient pressure, in Pascal, used for on-chip CO2 compensation.
+    /// Takes effect immediately, overriding sensor altitude compensation.
+    pub fn set_ambient_pressure(&mut self, pressure_pa: u32) -> Result<(), Error<I2C::Error>> {
+        let word = (pressure_pa / 100) as u16;
+        self.sensor
+            .write_words_command(&commands::SET_AMBIENT_PRESSURE, &[word])
+    }
+
+    /// Gets whether automatic self-calibration is enabled.
+    pub fn get_automatic_self_calibration_enabled(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let word = self
+            .sensor
+            .one_word_command(&commands::GET_AUTOMATIC_SELF_CALIBRATION_ENABLED)?;
+
+        Ok(word != 0)
+    }
+
+    /// Enables or disables automatic self-calibration.
+    pub fn set_automatic_self_calibration_enabled(
+        &mut self,
+        enabled: bool,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.write_words_command(
+            &commands::SET_AUTOMATIC_SELF_CALIBRATION_ENABLED,
+            &[enabled as u16],
+        )
+    }
+
+    /// Gets the CO2 concentration, in ppm, that automatic self-calibration assumes
+    /// as the lowest value the sensor sees over a self-calibration period.
+    pub fn get_automatic_self_calibration_target(&mut self) -> Result<u16, Error<I2C::Error>> {
+        self.sensor
+            .one_word_command(&commands::GET_AUTOMATIC_SELF_CALIBRATION_TARGET)
+    }
+
+    /// Sets the CO2 concentration, in ppm, that automatic self-calibration assumes
+    /// as the lowest value the sensor sees over a self-calibration period.
+    pub fn set_automatic_self_calibration_target(
+        &mut self,
+        target_co2_ppm: u16,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.write_words_command(
+            &commands::SET_AUTOMATIC_SELF_CALIBRATION_TARGET,
+            &[target_co2_ppm],
+        )
+    }
+
+    /// Gets the number of hours automatic self-calibration waits after power-on
+    /// before the first correction (SCD41/SCD43 only).
+    pub fn get_automatic_self_calibration_initial_period(
+        &mut self,
+    ) -> Result<u16, Error<I2C::Error>> {
+        self.sensor
+            .one_word_command(&commands::GET_AUTOMATIC_SELF_CALIBRATION_INITIAL_PERIOD)
+    }
+
+    /// Sets the number of hours automatic self-calibration waits after power-on
+    /// before the first correction (SCD41/SCD43 only). Must be an integer multiple
+    /// of 4 hours.
+    pub fn set_automatic_self_calibration_initial_period(
+        &mut self,
+        hours: u16,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.write_words_command(
+            &commands::SET_AUTOMATIC_SELF_CALIBRATION_INITIAL_PERIOD,
+            &[hours],
+        )
+    }
+
+    /// Gets the number of hours between automatic self-calibration corrections
+    /// during steady-state operation (SCD41/SCD43 only).
+    pub fn get_automatic_self_calibration_standard_period(
+        &mut self,
+    ) -> Result<u16, Error<I2C::Error>> {
+        self.sensor
+            .one_word_command(&commands::GET_AUTOMATIC_SELF_CALIBRATION_STANDARD_PERIOD)
+    }
+
+    /// Sets the number of hours between automatic self-calibration corrections
+    /// during steady-state operation (SCD41/SCD43 only). Must be an integer
+    /// multiple of 4 hours.
+    pub fn set_automatic_self_calibration_standard_period(
+        &mut self,
+        hours: u16,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.write_words_command(
+            &commands::SET_AUTOMATIC_SELF_CALIBRATION_STANDARD_PERIOD,
+            &[hours],
+        )
+    }
+
+    /// Forces recalibration of the sensor against a known CO2 concentration.
+    /// Returns the correction applied in ppm, or `None` if the sensor reports the
+    /// recalibration failed (e.g. no periodic measurement was running beforehand).
+    pub fn perform_forced_recalibration<Waiter: embedded_hal::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+        target_co2_ppm: u16,
+    ) -> Result<Option<i16>, Error<I2C::Error>> {
+        self.sensor
+            .write_words_command(&commands::PERFORM_FORCED_RECALIBRATION, &[target_co2_ppm])?;
+        waiter.delay_ms(400);
+        let word = self.sensor.read_response_word()?;
+
+        if word == 0xffff {
+            Ok(None)
+        } else {
+            Ok(Some((word as i32 - 0x8000) as i16))
+        }
+    }
+
+    /// Persists the current configuration (temperature offset, sensor altitude,
+    /// automatic self-calibration settings) to the sensor's non-volatile memory, so
+    /// it survives a power cycle.
+    pub fn persist_settings<Waiter: embedded_hal::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command(&commands::PERSIST_SETTINGS)?;
+        waiter.delay_ms(800);
+        Ok(())
+    }
+
+    /// Starts low-power periodic measurement, which updates a new measurement
+    /// every 30 seconds instead of every 5, trading accuracy for lower average
+    /// current draw.
+    pub fn start_low_power_periodic_measurement(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command(&commands::START_LOW_POWER_PERIODIC_MEASUREMENT)
+    }
+
+    /// Powers down the sensor to minimize current consumption. Only valid when no
+    /// periodic measurement is running; call [`Self::wake_up`] before sending any
+    /// other command.
+    pub fn power_down(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command(&commands::POWER_DOWN)
+    }
+
+    /// Wakes the sensor from [`Self::power_down`]. The sensor does not acknowledge
+    /// this command on the bus, so an I2C NACK here is expected and ignored; wait
+    /// at least 30 ms afterwards before sending any other command.
+    pub fn wake_up(&mut self) -> Result<(), Error<I2C::Error>> {
+        let _ = self.sensor.send_command(&commands::WAKE_UP);
+        Ok(())
+    }
+
+    /// Reinitializes the sensor, reloading saved settings from EEPROM. Must only
+    /// be called after [`Self::stop_periodic_measurement`].
+    pub fn reinit<Waiter: embedded_hal::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command(&commands::REINIT)?;
+        waiter.delay_ms(30);
+        Ok(())
+    }
+
+    /// Resets all configuration settings to factory defaults and erases
+    /// calibration history. Must only be called after
+    /// [`Self::stop_periodic_measurement`].
+    pub fn perform_factory_reset<Waiter: embedded_hal::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command(&commands::PERFORM_FACTORY_RESET)?;
+        waiter.delay_ms(1200);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C: embedded_hal_async::i2c::I2c> SCD4x<I2C> {
+    /// Async mirror of [`Self::get_data_ready_status`].
+    pub async fn get_data_ready_status_async(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let status = self
+            .sensor
+            .one_word_command_async(&commands::GET_DATA_READY_STATUS)
+            .await?;
+
+        Ok((status & 0x7FF) != 0)
+    }
+
+    /// Async mirror of [`Self::get_serial_number`].
+    pub async fn get_serial_number_async(&mut self) -> Result<u64, Error<I2C::Error>> {
+        let words = self
+            .sensor
+            .three_words_command_async(&commands::GET_SERIAL_NUMBER)
+            .await?;
+
+        Ok((words[0] as u64) << 32 | (words[1] as u64) << 16 | (words[2] as u64))
+    }
+
+    /// Async mirror of [`Self::get_sensor_variant`].
+    pub async fn get_sensor_variant_async(&mut self) -> Result<Variant, Error<I2C::Error>> {
+        let status = self
+            .sensor
+            .one_word_command_async(&commands::GET_SENSOR_VARIANT)
+            .await?;
+
+        match status >> 12 {
+            0b0000 => Ok(Variant::SCD40),
+            0b0001 => Ok(Variant::SCD41),
+            0b0101 => Ok(Variant::SCD43),
+            _ => Err(Error::InvalidResponse),
+        }
+    }
+
+    /// Async mirror of [`Self::read_measurement`].
+    pub async fn read_measurement_async(&mut self) -> Result<Measurement, Error<I2C::Error>> {
+        let words = self
+            .sensor
+            .three_words_command_async(&commands::READ_MEASUREMENT)
+            .await?;
+
+        Ok(Measurement::from_words(words))
+    }
+
+    /// Async mirror of [`Self::start_periodic_measurement`].
+    pub async fn start_periodic_measurement_async(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::START_PERIODIC_MEASUREMENTS)
+            .await
+    }
+
+    /// Async mirror of [`Self::stop_periodic_measurement`].
+    pub async fn stop_periodic_measurement_async(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::STOP_PERIODIC_MEASUREMENTS)
+            .await
+    }
+
+    /// Async mirror of [`Self::measure_single_shot`].
+    pub async fn measure_single_shot_async(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::MEASURE_SINGLE_SHOT)
+            .await
+    }
+
+    /// Async mirror of [`Self::measure_single_shot_rht_only`].
+    pub async fn measure_single_shot_rht_only_async(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::MEASURE_SINGLE_SHOT_RHT_ONLY)
+            .await
+    }
+
+    /// Async mirror of [`Self::perform_forced_recalibration`].
+    pub async fn perform_forced_recalibration_async<Waiter: embedded_hal_async::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+        target_co2_ppm: u16,
+    ) -> Result<Option<i16>, Error<I2C::Error>> {
+        self.sensor
+            .write_words_command_async(&commands::PERFORM_FORCED_RECALIBRATION, &[target_co2_ppm])
+            .await?;
+        waiter.delay_ms(400).await;
+        let word = self.sensor.read_response_word_async().await?;
+
+        if word == 0xffff {
+            Ok(None)
+        } else {
+            Ok(Some((word as i32 - 0x8000) as i16))
+        }
+    }
+
+    /// Async mirror of [`Self::persist_settings`].
+    pub async fn persist_settings_async<Waiter: embedded_hal_async::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::PERSIST_SETTINGS)
+            .await?;
+        waiter.delay_ms(800).await;
+        Ok(())
+    }
+
+    /// Async mirror of [`Self::start_low_power_periodic_measurement`].
+    pub async fn start_low_power_periodic_measurement_async(
+        &mut self,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::START_LOW_POWER_PERIODIC_MEASUREMENT)
+            .await
+    }
+
+    /// Async mirror of [`Self::power_down`].
+    pub async fn power_down_async(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command_async(&commands::POWER_DOWN).await
+    }
+
+    /// Async mirror of [`Self::wake_up`].
+    pub async fn wake_up_async(&mut self) -> Result<(), Error<I2C::Error>> {
+        let _ = self.sensor.send_command_async(&commands::WAKE_UP).await;
+        Ok(())
+    }
+
+    /// Async mirror of [`Self::reinit`].
+    pub async fn reinit_async<Waiter: embedded_hal_async::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor.send_command_async(&commands::REINIT).await?;
+        waiter.delay_ms(30).await;
+        Ok(())
+    }
+
+    /// Async mirror of [`Self::perform_factory_reset`].
+    pub async fn perform_factory_reset_async<Waiter: embedded_hal_async::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.sensor
+            .send_command_async(&commands::PERFORM_FACTORY_RESET)
+            .await?;
+        waiter.delay_ms(1200).await;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -140,6 +558,7 @@ mod tests {
 
                     Ok(())
                 }
+                [Operation::Write(_)] => Ok(()),
                 // Other transactions are invalid
                 _ => Err(DummyError::InvalidTest),
             }
@@ -231,4 +650,146 @@ mod tests {
             Ok(super::Variant::SCD43)
         ));
     }
+
+    #[test]
+    fn test_read_measurement() {
+        let bus = DummyBus {
+            response: &[0x03, 0x20, 0x2a, 0x66, 0x66, 0x93, 0x7f, 0xff, 0x8f],
+        };
+        let mut sensor = SCD4x::new(bus);
+
+        let measurement = sensor.read_measurement().unwrap();
+        assert_eq!(measurement.co2_ppm, 800);
+        assert!((measurement.temperature_c - 25.0).abs() < 0.01);
+        assert!((measurement.humidity_rh - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_start_periodic_measurement() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.start_periodic_measurement(), Ok(()));
+    }
+
+    #[test]
+    fn test_stop_periodic_measurement() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.stop_periodic_measurement(), Ok(()));
+    }
+
+    #[test]
+    fn test_measure_single_shot() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.measure_single_shot(), Ok(()));
+    }
+
+    #[test]
+    fn test_measure_single_shot_rht_only() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.measure_single_shot_rht_only(), Ok(()));
+    }
+
+    #[test]
+    fn test_get_temperature_offset() {
+        let bus = DummyBus {
+            response: &[0x05, 0xd9, 0x7a],
+        };
+        let mut sensor = SCD4x::new(bus);
+
+        assert!((sensor.get_temperature_offset().unwrap() - 4.0).abs() < 0.01);
+    }
+
+    struct NoopDelay;
+
+    impl embedded_hal::delay::DelayNs for NoopDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    #[test]
+    fn test_perform_forced_recalibration_success() {
+        let bus = DummyBus {
+            response: &[0x80, 0x02, 0xc0],
+        };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(
+            sensor.perform_forced_recalibration(&mut NoopDelay, 400),
+            Ok(Some(2))
+        );
+    }
+
+    #[test]
+    fn test_perform_forced_recalibration_failure() {
+        let bus = DummyBus {
+            response: &[0xff, 0xff, 0xac],
+        };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(
+            sensor.perform_forced_recalibration(&mut NoopDelay, 400),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_power_down() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.power_down(), Ok(()));
+    }
+
+    struct NackBus;
+
+    impl embedded_hal::i2c::ErrorType for NackBus {
+        type Error = DummyError;
+    }
+
+    impl embedded_hal::i2c::I2c for NackBus {
+        fn transaction(
+            &mut self,
+            _address: u8,
+            _operations: &mut [embedded_hal::i2c::Operation],
+        ) -> Result<(), Self::Error> {
+            Err(DummyError::InvalidTest)
+        }
+    }
+
+    #[test]
+    fn test_wake_up_tolerates_nack() {
+        let mut sensor = SCD4x::new(NackBus);
+
+        assert_eq!(sensor.wake_up(), Ok(()));
+    }
+
+    #[test]
+    fn test_reinit() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.reinit(&mut NoopDelay), Ok(()));
+    }
+
+    #[test]
+    fn test_start_low_power_periodic_measurement() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.start_low_power_periodic_measurement(), Ok(()));
+    }
+
+    #[test]
+    fn test_perform_factory_reset() {
+        let bus = DummyBus { response: &[] };
+        let mut sensor = SCD4x::new(bus);
+
+        assert_eq!(sensor.perform_factory_reset(&mut NoopDelay), Ok(()));
+    }
 }
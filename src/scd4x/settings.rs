@@ -0,0 +1,177 @@
+//! Batches several SCD4x compensation/calibration settings into a single
+//! [`SettingsBuilder::apply`] call, so a one-shot configuration only needs one
+//! [`super::SCD4x::persist_settings`] at the end instead of one per setter.
+
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+
+use super::SCD4x;
+use crate::sensirion::Error;
+
+/// Builder for batching SCD4x compensation/calibration settings.
+///
+/// # Example
+///
+/// ```ignore
+/// SettingsBuilder::new()
+///     .temperature_offset(4.0)
+///     .sensor_altitude(450)
+///     .automatic_self_calibration_enabled(true)
+///     .apply(&mut sensor, &mut delay)?;
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SettingsBuilder {
+    temperature_offset: Option<f32>,
+    sensor_altitude: Option<u16>,
+    ambient_pressure: Option<u32>,
+    automatic_self_calibration_enabled: Option<bool>,
+    automatic_self_calibration_target: Option<u16>,
+    automatic_self_calibration_initial_period: Option<u16>,
+    automatic_self_calibration_standard_period: Option<u16>,
+}
+
+impl SettingsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn temperature_offset(mut self, offset_c: f32) -> Self {
+        self.temperature_offset = Some(offset_c);
+        self
+    }
+
+    pub fn sensor_altitude(mut self, altitude_m: u16) -> Self {
+        self.sensor_altitude = Some(altitude_m);
+        self
+    }
+
+    pub fn ambient_pressure(mut self, pressure_pa: u32) -> Self {
+        self.ambient_pressure = Some(pressure_pa);
+        self
+    }
+
+    pub fn automatic_self_calibration_enabled(mut self, enabled: bool) -> Self {
+        self.automatic_self_calibration_enabled = Some(enabled);
+        self
+    }
+
+    pub fn automatic_self_calibration_target(mut self, target_co2_ppm: u16) -> Self {
+        self.automatic_self_calibration_target = Some(target_co2_ppm);
+        self
+    }
+
+    pub fn automatic_self_calibration_initial_period(mut self, hours: u16) -> Self {
+        self.automatic_self_calibration_initial_period = Some(hours);
+        self
+    }
+
+    pub fn automatic_self_calibration_standard_period(mut self, hours: u16) -> Self {
+        self.automatic_self_calibration_standard_period = Some(hours);
+        self
+    }
+
+    /// Writes every configured setting to `sensor`, then calls
+    /// [`SCD4x::persist_settings`] so the settings it covers survive a power
+    /// cycle. Ambient pressure is written but is not among the persisted settings.
+    pub fn apply<I2C: I2c>(
+        self,
+        sensor: &mut SCD4x<I2C>,
+        waiter: &mut impl DelayNs,
+    ) -> Result<(), Error<I2C::Error>> {
+        if let Some(offset_c) = self.temperature_offset {
+            sensor.set_temperature_offset(offset_c)?;
+        }
+        if let Some(altitude_m) = self.sensor_altitude {
+            sensor.set_sensor_altitude(altitude_m)?;
+        }
+        if let Some(pressure_pa) = self.ambient_pressure {
+            sensor.set_ambient_pressure(pressure_pa)?;
+        }
+        if let Some(enabled) = self.automatic_self_calibration_enabled {
+            sensor.set_automatic_self_calibration_enabled(enabled)?;
+        }
+        if let Some(target_co2_ppm) = self.automatic_self_calibration_target {
+            sensor.set_automatic_self_calibration_target(target_co2_ppm)?;
+        }
+        if let Some(hours) = self.automatic_self_calibration_initial_period {
+            sensor.set_automatic_self_calibration_initial_period(hours)?;
+        }
+        if let Some(hours) = self.automatic_self_calibration_standard_period {
+            sensor.set_automatic_self_calibration_standard_period(hours)?;
+        }
+
+        sensor.persist_settings(waiter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SettingsBuilder;
+    use crate::scd4x::SCD4x;
+    use embedded_hal::i2c::{Error, ErrorType, Operation};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum DummyError {
+        InvalidTest,
+    }
+
+    impl Error for DummyError {
+        fn kind(&self) -> embedded_hal::i2c::ErrorKind {
+            embedded_hal::i2c::ErrorKind::Other
+        }
+    }
+
+    struct NoopDelay;
+
+    impl embedded_hal::delay::DelayNs for NoopDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    // Records every command it's sent, sharing the log with the test via `Rc` so
+    // it can be inspected after the bus has been moved into an `SCD4x`.
+    struct RecordingBus {
+        writes: Rc<RefCell<Vec<Vec<u8>>>>,
+    }
+
+    impl ErrorType for RecordingBus {
+        type Error = DummyError;
+    }
+
+    impl embedded_hal::i2c::I2c for RecordingBus {
+        fn transaction(
+            &mut self,
+            _address: u8,
+            operations: &mut [Operation],
+        ) -> Result<(), Self::Error> {
+            match operations {
+                [Operation::Write(data)] => {
+                    self.writes.borrow_mut().push(data.to_vec());
+                    Ok(())
+                }
+                _ => Err(DummyError::InvalidTest),
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_writes_settings_then_persists() {
+        let writes = Rc::new(RefCell::new(Vec::new()));
+        let bus = RecordingBus {
+            writes: writes.clone(),
+        };
+        let mut sensor = SCD4x::new(bus);
+
+        SettingsBuilder::new()
+            .sensor_altitude(450)
+            .automatic_self_calibration_enabled(true)
+            .apply(&mut sensor, &mut NoopDelay)
+            .unwrap();
+
+        let writes = writes.borrow();
+        assert_eq!(writes.len(), 3);
+        assert_eq!(&writes[0][0..2], &[0x24, 0x27]); // SET_SENSOR_ALTITUDE
+        assert_eq!(&writes[1][0..2], &[0x24, 0x16]); // SET_AUTOMATIC_SELF_CALIBRATION_ENABLED
+        assert_eq!(&writes[2][0..2], &[0x36, 0x15]); // PERSIST_SETTINGS
+    }
+}
@@ -25,6 +25,7 @@ where
     }
 }
 
+#[derive(Debug)]
 pub struct Sensor<I2C> {
     i2c: I2C,
     addr: u8,
@@ -64,8 +65,12 @@ impl<I2C> Sensor<I2C> {
     }
 }
 
+// Writing more than this many data words in a single command would overflow the
+// stack buffer in `write_words_command`. Bump it if a future command needs more.
+const MAX_COMMAND_WORDS: usize = 2;
+
 impl<I2C: I2c> Sensor<I2C> {
-    pub fn read_word(&mut self, cmd: &Cmd) -> Result<u16, Error<I2C::Error>> {
+    pub fn one_word_command(&mut self, cmd: &Cmd) -> Result<u16, Error<I2C::Error>> {
         let mut result = [0u8; 3];
 
         self.i2c.write_read(self.addr, cmd, &mut result)?;
@@ -74,7 +79,7 @@ impl<I2C: I2c> Sensor<I2C> {
         Ok(u16::from_be_bytes([result[0], result[1]]))
     }
 
-    pub fn read_three_words(&mut self, cmd: &Cmd) -> Result<[u16; 3], Error<I2C::Error>> {
+    pub fn three_words_command(&mut self, cmd: &Cmd) -> Result<[u16; 3], Error<I2C::Error>> {
         let mut result = [0u8; 9];
 
         self.i2c.write_read(self.addr, cmd, &mut result)?;
@@ -88,6 +93,118 @@ impl<I2C: I2c> Sensor<I2C> {
             u16::from_be_bytes([result[6], result[7]]),
         ])
     }
+
+    /// Sends a command with no data words and does not wait for a response.
+    pub fn send_command(&mut self, cmd: &Cmd) -> Result<(), Error<I2C::Error>> {
+        self.i2c.write(self.addr, cmd)?;
+        Ok(())
+    }
+
+    /// Sends a command followed by `words`, each written big-endian with its own
+    /// appended CRC byte, as required by the Sensirion command protocol.
+    pub fn write_words_command(
+        &mut self,
+        cmd: &Cmd,
+        words: &[u16],
+    ) -> Result<(), Error<I2C::Error>> {
+        debug_assert!(words.len() <= MAX_COMMAND_WORDS);
+
+        let mut buf = [0u8; 2 + MAX_COMMAND_WORDS * 3];
+        buf[0..2].copy_from_slice(cmd);
+
+        let mut offset = 2;
+        for word in words {
+            let bytes = word.to_be_bytes();
+            buf[offset..offset + 2].copy_from_slice(&bytes);
+            buf[offset + 2] = Self::crc(&bytes);
+            offset += 3;
+        }
+
+        self.i2c.write(self.addr, &buf[..offset])?;
+        Ok(())
+    }
+
+    /// Reads back a single CRC-checked data word without writing a command first,
+    /// for commands whose response is only ready some time after it was sent.
+    pub fn read_response_word(&mut self) -> Result<u16, Error<I2C::Error>> {
+        let mut result = [0u8; 3];
+
+        self.i2c.read(self.addr, &mut result)?;
+        Self::check_crc(&result)?;
+
+        Ok(u16::from_be_bytes([result[0], result[1]]))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C: embedded_hal_async::i2c::I2c> Sensor<I2C> {
+    pub async fn one_word_command_async(&mut self, cmd: &Cmd) -> Result<u16, Error<I2C::Error>> {
+        let mut result = [0u8; 3];
+
+        self.i2c.write_read(self.addr, cmd, &mut result).await?;
+        Self::check_crc(&result)?;
+
+        Ok(u16::from_be_bytes([result[0], result[1]]))
+    }
+
+    pub async fn three_words_command_async(
+        &mut self,
+        cmd: &Cmd,
+    ) -> Result<[u16; 3], Error<I2C::Error>> {
+        let mut result = [0u8; 9];
+
+        self.i2c.write_read(self.addr, cmd, &mut result).await?;
+        for piece in result.as_chunks::<3>().0 {
+            Self::check_crc(piece)?;
+        }
+
+        Ok([
+            u16::from_be_bytes([result[0], result[1]]),
+            u16::from_be_bytes([result[3], result[4]]),
+            u16::from_be_bytes([result[6], result[7]]),
+        ])
+    }
+
+    /// Sends a command with no data words and does not wait for a response.
+    pub async fn send_command_async(&mut self, cmd: &Cmd) -> Result<(), Error<I2C::Error>> {
+        self.i2c.write(self.addr, cmd).await?;
+        Ok(())
+    }
+
+    /// Sends a command followed by `words`, each written big-endian with its own
+    /// appended CRC byte, as required by the Sensirion command protocol.
+    pub async fn write_words_command_async(
+        &mut self,
+        cmd: &Cmd,
+        words: &[u16],
+    ) -> Result<(), Error<I2C::Error>> {
+        debug_assert!(words.len() <= MAX_COMMAND_WORDS);
+
+        let mut buf = [0u8; 2 + MAX_COMMAND_WORDS * 3];
+        buf[0..2].copy_from_slice(cmd);
+
+        let mut offset = 2;
+        for word in words {
+            let bytes = word.to_be_bytes();
+            buf[offset..offset + 2].copy_from_slice(&bytes);
+            buf[offset + 2] = Self::crc(&bytes);
+            offset += 3;
+        }
+
+        self.i2c.write(self.addr, &buf[..offset]).await?;
+        Ok(())
+    }
+
+    /// Reads back a single CRC-checked data word without writing a command first,
+    /// for commands whose response is only ready some time after it was sent.
+    pub async fn read_response_word_async(&mut self) -> Result<u16, Error<I2C::Error>> {
+        let mut result = [0u8; 3];
+
+        self.i2c.read(self.addr, &mut result).await?;
+        Self::check_crc(&result)?;
+
+        Ok(u16::from_be_bytes([result[0], result[1]]))
+    }
 }
 
 #[cfg(test)]
@@ -115,4 +232,87 @@ mod tests {
             Err(super::Error::InvalidCrc)
         );
     }
+
+    #[cfg(feature = "async")]
+    mod async_tests {
+        use super::super::Sensor;
+        use embedded_hal::i2c::{ErrorType, Operation};
+        use embedded_hal_async::i2c::I2c;
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum AsyncDummyError {
+            InvalidTest,
+        }
+
+        impl embedded_hal::i2c::Error for AsyncDummyError {
+            fn kind(&self) -> embedded_hal::i2c::ErrorKind {
+                embedded_hal::i2c::ErrorKind::Other
+            }
+        }
+
+        struct AsyncDummyBus<'a> {
+            response: &'a [u8],
+        }
+
+        impl ErrorType for AsyncDummyBus<'_> {
+            type Error = AsyncDummyError;
+        }
+
+        impl I2c for AsyncDummyBus<'_> {
+            async fn transaction(
+                &mut self,
+                _address: u8,
+                operations: &mut [Operation<'_>],
+            ) -> Result<(), Self::Error> {
+                match operations {
+                    [Operation::Write(_), Operation::Read(response)] => {
+                        if response.len() != self.response.len() {
+                            return Err(AsyncDummyError::InvalidTest);
+                        }
+
+                        response.copy_from_slice(self.response);
+
+                        Ok(())
+                    }
+                    _ => Err(AsyncDummyError::InvalidTest),
+                }
+            }
+        }
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        // A runtime-free executor good enough for futures that never actually
+        // yield, like the mock I2C bus above.
+        fn block_on<F: Future>(fut: F) -> F::Output {
+            let waker = Waker::from(Arc::new(NoopWaker));
+            let mut cx = Context::from_waker(&waker);
+            let mut fut = std::pin::pin!(fut);
+
+            loop {
+                if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+                    return value;
+                }
+            }
+        }
+
+        #[test]
+        fn test_one_word_command_async() {
+            let bus = AsyncDummyBus {
+                response: &[0xbe, 0xef, 0x92],
+            };
+            let mut sensor = Sensor::new(bus, 0x59);
+
+            assert_eq!(
+                block_on(sensor.one_word_command_async(&[0x00, 0x00])),
+                Ok(0xbeef)
+            );
+        }
+    }
 }
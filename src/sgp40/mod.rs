@@ -38,4 +38,68 @@ impl<I2C: I2c> SGP40<I2C> {
 
         Ok((words[0] as u64) << 32 | (words[1] as u64) << 16 | (words[2] as u64))
     }
+
+    /// Triggers a raw VOC measurement, compensated with the given relative humidity
+    /// (in %RH) and temperature (in degrees Celsius), and returns the raw SRAW tick
+    /// value.
+    pub fn measure_raw_signal<Waiter: embedded_hal::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+        humidity_rh: f32,
+        temperature_c: f32,
+    ) -> Result<u16, Error<I2C::Error>> {
+        let rh_ticks = (humidity_rh * 65535.0 / 100.0) as u16;
+        let t_ticks = ((temperature_c + 45.0) * 65535.0 / 175.0) as u16;
+
+        self.sensor
+            .write_words_command(&commands::MEASURE_RAW_SIGNAL, &[rh_ticks, t_ticks])?;
+        waiter.delay_ms(30);
+        self.sensor.read_response_word()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C: embedded_hal_async::i2c::I2c> SGP40<I2C> {
+    /// Async mirror of [`Self::measure_raw_signal`].
+    pub async fn measure_raw_signal_async<Waiter: embedded_hal_async::delay::DelayNs>(
+        &mut self,
+        waiter: &mut Waiter,
+        humidity_rh: f32,
+        temperature_c: f32,
+    ) -> Result<u16, Error<I2C::Error>> {
+        let rh_ticks = (humidity_rh * 65535.0 / 100.0) as u16;
+        let t_ticks = ((temperature_c + 45.0) * 65535.0 / 175.0) as u16;
+
+        self.sensor
+            .write_words_command_async(&commands::MEASURE_RAW_SIGNAL, &[rh_ticks, t_ticks])
+            .await?;
+        waiter.delay_ms(30).await;
+        self.sensor.read_response_word_async().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SGP40;
+    use crate::debug_utils::DummyBus;
+
+    struct NoopDelay;
+
+    impl embedded_hal::delay::DelayNs for NoopDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    #[test]
+    fn test_measure_raw_signal() {
+        let bus = DummyBus {
+            response: &[0x1a, 0x4b, 0xd6],
+        };
+        let mut sensor = SGP40::new(bus);
+        let mut delay = NoopDelay;
+
+        assert_eq!(
+            sensor.measure_raw_signal(&mut delay, 50.0, 25.0),
+            Ok(0x1a4b)
+        );
+    }
 }
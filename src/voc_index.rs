@@ -0,0 +1,186 @@
+//! VOC Index post-processing for raw SGP40 ticks.
+//!
+//! The SGP40 itself only reports a raw SRAW signal (see
+//! [`crate::sgp40::SGP40::measure_raw_signal`]); this module implements Sensirion's
+//! adaptive gas-index algorithm that turns a 1 Hz stream of SRAW ticks into a VOC
+//! Index in the range 1-500, centered at 100 for typical/baseline air.
+
+// Interval, in seconds, between the samples the algorithm is fed.
+const SAMPLING_INTERVAL_S: f32 = 1.0;
+
+// Number of initial samples during which the baseline is tracked with a fast filter,
+// so a freshly powered-on sensor converges quickly instead of waiting hours.
+const INITIAL_SAMPLES: u32 = 45;
+const INITIAL_GAIN: f32 = 0.2;
+
+// Time constant of the steady-state low-pass filter, chosen so the baseline follows
+// slow drift (temperature, sensor aging) but not a single VOC event.
+const TAU_MEAN_S: f32 = 12.0 * 3600.0;
+
+// Default variability estimate before enough samples have been seen to measure it,
+// picked to be comparable to typical SRAW sensor noise.
+const INITIAL_STD: f32 = 500.0;
+const MIN_STD: f32 = 1.0;
+
+// An index at or above this is treated as an ongoing VOC event: baseline adaption is
+// paused so the event itself doesn't get learned in as the new normal.
+const GATING_INDEX_THRESHOLD: i32 = 150;
+
+// Upper bound on how long adaption can stay paused. If an event (or a genuine
+// baseline shift) lasts longer than this, adaption resumes anyway.
+const MAX_GATING_DURATION_S: f32 = 180.0 * 60.0;
+const MAX_GATING_SAMPLES: u32 = (MAX_GATING_DURATION_S / SAMPLING_INTERVAL_S) as u32;
+
+// Sigmoid shape parameters, chosen so that `offset == 0` (SRAW at the learned
+// baseline) maps to a VOC Index of 100.
+const SIGMOID_K: f32 = 1.0;
+const SIGMOID_X0: f32 = 1.386_294_4; // ln(4)
+
+const INDEX_MIN: f32 = 1.0;
+const INDEX_MAX: f32 = 500.0;
+
+/// Adaptive gas-index algorithm that converts raw SGP40 SRAW ticks into a VOC Index.
+///
+/// Feed it one SRAW tick per second via [`Self::process`]. It is independent of I2C
+/// and the `SGP40` driver, so it can be driven directly with recorded tick sequences
+/// in tests.
+#[derive(Debug, Clone, Copy)]
+pub struct VocAlgorithm {
+    mean: f32,
+    std: f32,
+    initialized: bool,
+    sample_count: u32,
+    gating_samples: u32,
+    // Set once a single gating window has run out while the index is still
+    // elevated, so adaption stays resumed for the rest of the event instead of
+    // immediately re-arming the freeze on the next elevated sample.
+    forced_resume: bool,
+}
+
+impl VocAlgorithm {
+    pub fn new() -> Self {
+        Self {
+            mean: 0.0,
+            std: INITIAL_STD,
+            initialized: false,
+            sample_count: 0,
+            gating_samples: 0,
+            forced_resume: false,
+        }
+    }
+
+    /// Processes the next 1 Hz SRAW tick and returns the current VOC Index (1-500).
+    pub fn process(&mut self, sraw: u16) -> i32 {
+        let sraw = sraw as f32;
+
+        if !self.initialized {
+            self.mean = sraw;
+            self.initialized = true;
+        }
+
+        let offset = self.mean - sraw;
+        let index = Self::sigmoid(offset / self.std);
+        let elevated = index >= GATING_INDEX_THRESHOLD;
+
+        if elevated && !self.forced_resume && self.gating_samples < MAX_GATING_SAMPLES {
+            self.gating_samples += 1;
+        } else {
+            // Either the index is back below threshold (normal adaption), or the
+            // gating window just ran out while the event is still ongoing. In the
+            // latter case latch `forced_resume` so adaption keeps running every
+            // sample for the rest of the event, instead of re-arming the freeze on
+            // the very next elevated sample.
+            self.forced_resume = elevated;
+            self.gating_samples = 0;
+
+            let gain = if self.sample_count < INITIAL_SAMPLES {
+                INITIAL_GAIN
+            } else {
+                Self::low_pass_gain(TAU_MEAN_S)
+            };
+            self.mean += gain * (sraw - self.mean);
+            self.std = (self.std + gain * (offset.abs() - self.std)).max(MIN_STD);
+            self.sample_count += 1;
+        }
+
+        index
+    }
+
+    fn low_pass_gain(tau_s: f32) -> f32 {
+        1.0 - libm::expf(-SAMPLING_INTERVAL_S / tau_s)
+    }
+
+    fn sigmoid(z: f32) -> i32 {
+        let index = 500.0 / (1.0 + libm::expf(-(z - SIGMOID_X0) * SIGMOID_K));
+        index.clamp(INDEX_MIN, INDEX_MAX) as i32
+    }
+}
+
+impl Default for VocAlgorithm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VocAlgorithm;
+
+    #[test]
+    fn test_baseline_settles_near_100() {
+        let mut algo = VocAlgorithm::new();
+        let mut index = 0;
+
+        for _ in 0..60 {
+            index = algo.process(30000);
+        }
+
+        assert!((90..=110).contains(&index), "index was {index}");
+    }
+
+    #[test]
+    fn test_sudden_voc_event_raises_index() {
+        let mut algo = VocAlgorithm::new();
+
+        for _ in 0..60 {
+            algo.process(30000);
+        }
+
+        let index = algo.process(20000);
+        assert!(index > 150, "index was {index}");
+    }
+
+    #[test]
+    fn test_long_voc_event_keeps_adapting_past_gating_cap() {
+        use super::MAX_GATING_SAMPLES;
+
+        let mut algo = VocAlgorithm::new();
+        for _ in 0..60 {
+            algo.process(30000);
+        }
+        let baseline_mean = algo.mean;
+
+        // Hold a sustained event well past the gating cap. If adaption only
+        // resumed for a single sample per cap (the bug), `mean` would barely
+        // have moved; with a proper forced-resume it should track the event.
+        for _ in 0..(2 * MAX_GATING_SAMPLES + 10) {
+            algo.process(20000);
+        }
+
+        let moved = baseline_mean - algo.mean;
+        assert!(
+            moved > 1000.0,
+            "mean only moved {moved} past the gating cap, forced-resume likely not adapting"
+        );
+    }
+
+    #[test]
+    fn test_index_stays_in_range() {
+        let mut algo = VocAlgorithm::new();
+
+        for sraw in [30000u16, 0, 65535, 30000, 15000, 45000] {
+            let index = algo.process(sraw);
+            assert!((1..=500).contains(&index));
+        }
+    }
+}